@@ -1,6 +1,13 @@
 use super::{Config, GlobalQueue, PoolContext, SchedUnit};
 use crossbeam_deque::Steal;
+use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::task::{Context as StdContext, Poll as StdPoll, RawWaker, RawWakerVTable, Waker};
+use std::thread;
+use std::time::Duration;
 
 pub enum Task<G>
 where
@@ -8,6 +15,302 @@ where
 {
     Once(Option<Box<dyn FnOnce(&mut Handle<'_, G>) + Send>>),
     Mut(Box<dyn FnMut(&mut Handle<'_, G>) + Send>),
+    Poll(Arc<PollSlot<G>>),
+}
+
+/// State of the future behind a `PollSlot`, held under `PollSlot::future`.
+///
+/// `Idle` and `Done` are what the `Mutex<Option<..>>` predecessor of this
+/// type used to encode (`Some`/`None`); `Running` is the state added to fix
+/// the race below — it is load-bearing, not just documentation, see `poll`.
+enum FutureSlot {
+    Idle(Pin<Box<dyn Future<Output = ()> + Send>>),
+    Running,
+    Done,
+}
+
+/// Shared state behind a spawned future: the future itself (taken out while
+/// being polled) and enough bookkeeping to re-push the task onto the pool
+/// when woken.
+pub struct PollSlot<G>
+where
+    G: GlobalQueue,
+{
+    future: Mutex<FutureSlot>,
+    // Set when a wake enqueues a `Task::Poll(..)` for this slot and cleared
+    // when the next poll takes the future, so that `wake`/`wake_by_ref` calls
+    // made before that poll (including ones racing with an in-flight poll)
+    // enqueue the task at most once.
+    scheduled: AtomicBool,
+    remote: super::Remote<G>,
+}
+
+impl<G> PollSlot<G>
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    fn new(future: Pin<Box<dyn Future<Output = ()> + Send>>, remote: super::Remote<G>) -> Self {
+        PollSlot {
+            future: Mutex::new(FutureSlot::Idle(future)),
+            scheduled: AtomicBool::new(false),
+            remote,
+        }
+    }
+
+    /// Polls the future until it either completes or genuinely has nothing
+    /// left to do for now. Returns `true` when the future has completed (on
+    /// this call or an earlier one), `false` if it's still pending, in which
+    /// case a future `wake` will re-enqueue it, or if another poll of this
+    /// slot is already running.
+    ///
+    /// A future that calls `wake`/`wake_by_ref` synchronously from inside its
+    /// own `poll` (e.g. a `yield_now`) would otherwise race: the synchronous
+    /// wake's `schedule()` call can enqueue a second `Task::Poll` for this
+    /// slot before this call's `future.poll` has returned, and that second
+    /// poll would see the future missing (taken by this call) and wrongly
+    /// report the task done, leaking it forever. Instead of letting that
+    /// second poll run concurrently, it sees `FutureSlot::Running`, does
+    /// nothing, and this call loops to re-poll itself once it notices
+    /// `scheduled` was re-armed while it was busy.
+    fn poll(self: &Arc<Self>) -> bool {
+        loop {
+            let mut future = {
+                let mut guard = self.future.lock().unwrap();
+                match std::mem::replace(&mut *guard, FutureSlot::Running) {
+                    FutureSlot::Idle(f) => {
+                        self.scheduled.store(false, Ordering::Release);
+                        f
+                    }
+                    // Another poll for this slot is already running on some
+                    // other thread (see the doc comment above); let it
+                    // finish and, if needed, loop on our behalf.
+                    FutureSlot::Running => return false,
+                    FutureSlot::Done => return true,
+                }
+            };
+            let waker = waker(self.clone());
+            let mut cx = StdContext::from_waker(&waker);
+            match future.as_mut().poll(&mut cx) {
+                StdPoll::Ready(()) => {
+                    *self.future.lock().unwrap() = FutureSlot::Done;
+                    return true;
+                }
+                StdPoll::Pending => {
+                    *self.future.lock().unwrap() = FutureSlot::Idle(future);
+                    if !self.scheduled.load(Ordering::Acquire) {
+                        return false;
+                    }
+                    // Woken again while we were the one holding the future;
+                    // no second `Task::Poll` got to run concurrently with us
+                    // (see above), so re-poll here instead of returning and
+                    // relying on nobody to ever pick this back up.
+                }
+            }
+        }
+    }
+
+    fn schedule(self: Arc<Self>) {
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            let slot = self.clone();
+            self.remote.spawn(Task::Poll(slot));
+        }
+    }
+}
+
+unsafe fn clone_waker<G>(data: *const ()) -> RawWaker
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    let slot = Arc::from_raw(data as *const PollSlot<G>);
+    std::mem::forget(slot.clone());
+    RawWaker::new(data, vtable::<G>())
+}
+
+unsafe fn wake_waker<G>(data: *const ())
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    Arc::from_raw(data as *const PollSlot<G>).schedule();
+}
+
+unsafe fn wake_by_ref_waker<G>(data: *const ())
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    let slot = Arc::from_raw(data as *const PollSlot<G>);
+    slot.clone().schedule();
+    std::mem::forget(slot);
+}
+
+unsafe fn drop_waker<G>(data: *const ())
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    drop(Arc::from_raw(data as *const PollSlot<G>));
+}
+
+fn vtable<G>() -> &'static RawWakerVTable
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    &RawWakerVTable::new(
+        clone_waker::<G>,
+        wake_waker::<G>,
+        wake_by_ref_waker::<G>,
+        drop_waker::<G>,
+    )
+}
+
+fn waker<G>(slot: Arc<PollSlot<G>>) -> Waker
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    let data = Arc::into_raw(slot) as *const ();
+    unsafe { Waker::from_raw(RawWaker::new(data, vtable::<G>())) }
+}
+
+/// Wraps a future so a panic from one of its `poll` calls is caught instead
+/// of unwinding out through it, matching the `catch_unwind`-then-mark-done
+/// pattern `spawn_once`/`spawn_mut`/`spawn_after` use for the same reason:
+/// without this, a panic inside `f` would unwind straight out of whatever
+/// is driving the future (e.g. `PollSlot::poll`) and skip the `task_done()`
+/// call that follows `f.await` in each `spawn_future` wrapper.
+struct CatchUnwindFuture<F> {
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> CatchUnwindFuture<F> {
+    fn new(f: F) -> Self {
+        CatchUnwindFuture { inner: Box::pin(f) }
+    }
+}
+
+impl<F: Future> Future for CatchUnwindFuture<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut StdContext<'_>) -> StdPoll<Self::Output> {
+        let inner = self.get_mut().inner.as_mut();
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| inner.poll(cx))) {
+            Ok(StdPoll::Ready(v)) => StdPoll::Ready(Ok(v)),
+            Ok(StdPoll::Pending) => StdPoll::Pending,
+            Err(payload) => StdPoll::Ready(Err(payload)),
+        }
+    }
+}
+
+/// Error returned by [`JoinHandle`] when the spawned task was dropped before
+/// it produced a result, e.g. because the pool is shutting down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+enum JoinState<R> {
+    Pending { waker: Option<Waker> },
+    Ready(R),
+    Cancelled,
+    Taken,
+}
+
+struct JoinInner<R> {
+    state: Mutex<JoinState<R>>,
+    cond: Condvar,
+}
+
+/// A handle to the result of a task spawned through `spawn_once_handle` or
+/// `spawn_future_handle`. Can be waited on synchronously with `join`, or
+/// polled as a `Future` from another task running on the same pool.
+pub struct JoinHandle<R> {
+    inner: Arc<JoinInner<R>>,
+}
+
+impl<R> JoinHandle<R> {
+    /// Blocks the calling thread until the task has produced a result.
+    pub fn join(self) -> Result<R, Cancelled> {
+        let mut state = self.inner.state.lock().unwrap();
+        loop {
+            match std::mem::replace(&mut *state, JoinState::Taken) {
+                JoinState::Ready(v) => return Ok(v),
+                JoinState::Cancelled => return Err(Cancelled),
+                JoinState::Taken => {
+                    panic!("`JoinHandle::join` called after the handle was polled to completion")
+                }
+                pending @ JoinState::Pending { .. } => {
+                    *state = pending;
+                    state = self.inner.cond.wait(state).unwrap();
+                }
+            }
+        }
+    }
+}
+
+impl<R> Future for JoinHandle<R> {
+    type Output = Result<R, Cancelled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut StdContext<'_>) -> StdPoll<Self::Output> {
+        let mut state = self.inner.state.lock().unwrap();
+        match std::mem::replace(&mut *state, JoinState::Taken) {
+            JoinState::Ready(v) => StdPoll::Ready(Ok(v)),
+            JoinState::Cancelled => StdPoll::Ready(Err(Cancelled)),
+            JoinState::Taken => panic!("`JoinHandle` polled after completion"),
+            JoinState::Pending { .. } => {
+                *state = JoinState::Pending {
+                    waker: Some(cx.waker().clone()),
+                };
+                StdPoll::Pending
+            }
+        }
+    }
+}
+
+/// The writing half of a `JoinHandle`'s single-shot channel. Completing it
+/// fills in the result; dropping it without completing (task got cancelled
+/// mid-flight) resolves the `JoinHandle` with `Cancelled` instead.
+struct JoinSender<R> {
+    inner: Arc<JoinInner<R>>,
+    completed: bool,
+}
+
+impl<R> JoinSender<R> {
+    fn new() -> (Self, JoinHandle<R>) {
+        let inner = Arc::new(JoinInner {
+            state: Mutex::new(JoinState::Pending { waker: None }),
+            cond: Condvar::new(),
+        });
+        (
+            JoinSender {
+                inner: inner.clone(),
+                completed: false,
+            },
+            JoinHandle { inner },
+        )
+    }
+
+    fn complete(mut self, value: R) {
+        let old = {
+            let mut state = self.inner.state.lock().unwrap();
+            std::mem::replace(&mut *state, JoinState::Ready(value))
+        };
+        self.inner.cond.notify_all();
+        if let JoinState::Pending { waker: Some(w) } = old {
+            w.wake();
+        }
+        self.completed = true;
+    }
+}
+
+impl<R> Drop for JoinSender<R> {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+        let old = {
+            let mut state = self.inner.state.lock().unwrap();
+            std::mem::replace(&mut *state, JoinState::Cancelled)
+        };
+        self.inner.cond.notify_all();
+        if let JoinState::Pending { waker: Some(w) } = old {
+            w.wake();
+        }
+    }
 }
 
 impl<G> AsMut<Self> for Task<G>
@@ -56,6 +359,10 @@ where
                 (r.take().unwrap())(&mut handle);
                 return true;
             }
+            Task::Poll(slot) => {
+                let slot = slot.clone();
+                return slot.poll();
+            }
         }
         ctx.spawn(task);
         false
@@ -82,6 +389,36 @@ where
         self.ctx.spawn(Task::Mut(Box::new(t)));
     }
 
+    pub fn spawn_future(&mut self, f: impl Future<Output = ()> + Send + 'static) {
+        let remote = self.ctx.remote();
+        let slot = Arc::new(PollSlot::new(Box::pin(f), remote));
+        self.ctx.spawn(Task::Poll(slot));
+    }
+
+    pub fn spawn_once_handle<R>(
+        &mut self,
+        t: impl FnOnce(&mut Handle<'_, G>) -> R + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_once(move |h| sender.complete(t(h)));
+        handle
+    }
+
+    pub fn spawn_future_handle<R>(
+        &mut self,
+        f: impl Future<Output = R> + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_future(async move { sender.complete(f.await) });
+        handle
+    }
+
     pub fn rerun(&mut self) {
         self.rerun = true;
     }
@@ -111,6 +448,35 @@ where
     pub fn spawn_mut(&self, t: impl FnMut(&mut Handle<'_, G>) + Send + 'static) {
         self.remote.spawn(Task::Mut(Box::new(t)))
     }
+
+    pub fn spawn_future(&self, f: impl Future<Output = ()> + Send + 'static) {
+        let slot = Arc::new(PollSlot::new(Box::pin(f), self.remote.clone()));
+        self.remote.spawn(Task::Poll(slot));
+    }
+
+    pub fn spawn_once_handle<R>(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, G>) -> R + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_once(move |h| sender.complete(t(h)));
+        handle
+    }
+
+    pub fn spawn_future_handle<R>(
+        &self,
+        f: impl Future<Output = R> + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_future(async move { sender.complete(f.await) });
+        handle
+    }
 }
 
 pub struct RunnerFactory<G>
@@ -163,6 +529,344 @@ where
     pub fn spawn_mut(&self, t: impl FnMut(&mut Handle<'_, G>) + Send + 'static) {
         self.spawn(Task::Mut(Box::new(t)))
     }
+
+    pub fn spawn_future(&self, f: impl Future<Output = ()> + Send + 'static) {
+        let slot = Arc::new(PollSlot::new(Box::pin(f), self.remote()));
+        self.spawn(Task::Poll(slot));
+    }
+
+    pub fn spawn_once_handle<R>(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, G>) -> R + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_once(move |h| sender.complete(t(h)));
+        handle
+    }
+
+    pub fn spawn_future_handle<R>(
+        &self,
+        f: impl Future<Output = R> + Send + 'static,
+    ) -> JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_future(async move { sender.complete(f.await) });
+        handle
+    }
+}
+
+const WHEEL_LEVELS: usize = 6;
+const WHEEL_SLOTS: usize = 64;
+const WHEEL_SLOT_MASK: u64 = WHEEL_SLOTS as u64 - 1;
+
+/// Cancellation handle for a timer registered through `spawn_once_after` /
+/// `spawn_interval`. Dropping it does *not* cancel the timer; call `cancel`
+/// explicitly. The timer slot is simply skipped when its bucket fires, it is
+/// not removed from the wheel eagerly.
+pub struct TimerGuard {
+    dead: Arc<AtomicBool>,
+}
+
+impl TimerGuard {
+    pub fn cancel(&self) {
+        self.dead.store(true, Ordering::Release);
+    }
+}
+
+struct TimerEntry<G>
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    deadline: AtomicU64,
+    period: Option<u64>,
+    dead: Arc<AtomicBool>,
+    callback: Mutex<Box<dyn FnMut(&mut Handle<'_, G>) + Send>>,
+    remote: super::Remote<G>,
+}
+
+/// A newly registered timer, still expressed relative to its own
+/// registration time; the timer thread stamps it with an absolute deadline
+/// (current tick + delay) as soon as it picks it up, since "now" is only
+/// meaningful on that thread.
+struct PendingTimer<G>
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    delay_ticks: u64,
+    period_ticks: Option<u64>,
+    dead: Arc<AtomicBool>,
+    callback: Mutex<Box<dyn FnMut(&mut Handle<'_, G>) + Send>>,
+    remote: super::Remote<G>,
+}
+
+fn wheel_bucket(tick: u64, level: usize) -> usize {
+    ((tick >> (level * 6)) & WHEEL_SLOT_MASK) as usize
+}
+
+fn wheel_level(delta_ticks: u64) -> usize {
+    let mut level = 0;
+    let mut span = WHEEL_SLOTS as u64;
+    while delta_ticks >= span && level + 1 < WHEEL_LEVELS {
+        level += 1;
+        span *= WHEEL_SLOTS as u64;
+    }
+    level
+}
+
+/// A hierarchical timing wheel (6 levels of 64 buckets each) owned by a pool,
+/// used to submit `Task<G>`s at a future tick without blocking a worker
+/// thread on a sleep. A dedicated timer thread advances the wheel tick by
+/// tick, cascading entries from coarser levels into finer ones as their
+/// deadline approaches, and pushes expired entries onto the pool's
+/// `GlobalQueue` through the `Remote<G>` captured at registration time.
+///
+/// Dropping the wheel stops that thread: `sender` is torn down first (see
+/// `Drop`), which the thread notices the next time it drains its channel,
+/// and `worker` is then joined so the thread is actually gone by the time
+/// the drop completes.
+pub struct TimerWheel<G>
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    sender: Option<mpsc::Sender<PendingTimer<G>>>,
+    tick_duration: Duration,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl<G> TimerWheel<G>
+where
+    G: GlobalQueue<Task = Task<G>> + Send + Sync + 'static,
+{
+    pub fn new(tick_duration: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel::<PendingTimer<G>>();
+        let worker = thread::Builder::new()
+            .name("timer".to_owned())
+            .spawn(move || {
+                let mut levels: Vec<Vec<Vec<Arc<TimerEntry<G>>>>> = (0..WHEEL_LEVELS)
+                    .map(|_| (0..WHEEL_SLOTS).map(|_| Vec::new()).collect())
+                    .collect();
+                let mut tick: u64 = 0;
+                'ticks: loop {
+                    thread::sleep(tick_duration);
+                    tick += 1;
+
+                    loop {
+                        let pending = match receiver.try_recv() {
+                            Ok(pending) => pending,
+                            Err(mpsc::TryRecvError::Empty) => break,
+                            // The wheel was dropped; nothing left to admit
+                            // and nobody left to hand expired entries to.
+                            Err(mpsc::TryRecvError::Disconnected) => break 'ticks,
+                        };
+                        let entry = Arc::new(TimerEntry {
+                            deadline: AtomicU64::new(tick + pending.delay_ticks),
+                            period: pending.period_ticks,
+                            dead: pending.dead,
+                            callback: pending.callback,
+                            remote: pending.remote,
+                        });
+                        insert(&mut levels, tick, entry);
+                    }
+
+                    // Cascade: whenever a coarser level's own tick counter
+                    // wraps back to its current bucket, that bucket's
+                    // entries are now within range of the finer levels and
+                    // need to be redistributed.
+                    for level in 1..WHEEL_LEVELS {
+                        if tick.is_multiple_of((WHEEL_SLOTS as u64).pow(level as u32)) {
+                            let idx = wheel_bucket(tick, level);
+                            let due = std::mem::take(&mut levels[level][idx]);
+                            for entry in due {
+                                insert(&mut levels, tick, entry);
+                            }
+                        }
+                    }
+
+                    let idx = wheel_bucket(tick, 0);
+                    let due = std::mem::take(&mut levels[0][idx]);
+                    for entry in due {
+                        if entry.dead.load(Ordering::Acquire) {
+                            continue;
+                        }
+                        let fire = entry.clone();
+                        entry.remote.spawn(Task::Once(Some(Box::new(
+                            move |h: &mut Handle<'_, G>| {
+                                (fire.callback.lock().unwrap())(h);
+                            },
+                        ))));
+                        if let Some(period) = entry.period {
+                            if !entry.dead.load(Ordering::Acquire) {
+                                entry
+                                    .deadline
+                                    .fetch_add(period.max(1), Ordering::Relaxed);
+                                insert(&mut levels, tick, entry);
+                            }
+                        }
+                    }
+                }
+            })
+            .expect("failed to spawn timer thread");
+        TimerWheel {
+            sender: Some(sender),
+            tick_duration,
+            worker: Some(worker),
+        }
+    }
+
+    fn submit(
+        &self,
+        remote: super::Remote<G>,
+        delay: Duration,
+        period: Option<Duration>,
+        callback: Box<dyn FnMut(&mut Handle<'_, G>) + Send>,
+    ) -> TimerGuard {
+        let to_ticks = |d: Duration| (nanos(d) / nanos(self.tick_duration).max(1)).max(1);
+        let dead = Arc::new(AtomicBool::new(false));
+        let pending = PendingTimer {
+            delay_ticks: to_ticks(delay),
+            period_ticks: period.map(to_ticks),
+            dead: dead.clone(),
+            callback: Mutex::new(callback),
+            remote,
+        };
+        // The timer thread is the sole reader; if it has already exited the
+        // entry is simply never scheduled.
+        let _ = self.sender.as_ref().unwrap().send(pending);
+        TimerGuard { dead }
+    }
+}
+
+impl<G> Drop for TimerWheel<G>
+where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    fn drop(&mut self) {
+        // Drop the sender first so the timer thread's next channel drain
+        // sees `Disconnected` and stops, then wait for it to actually exit.
+        self.sender.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_secs() * 1_000_000_000 + u64::from(d.subsec_nanos())
+}
+
+fn insert<G>(
+    levels: &mut [Vec<Vec<Arc<TimerEntry<G>>>>],
+    now: u64,
+    entry: Arc<TimerEntry<G>>,
+) where
+    G: GlobalQueue<Task = Task<G>>,
+{
+    let deadline = entry.deadline.load(Ordering::Relaxed);
+    let delta = deadline.saturating_sub(now).max(1);
+    let level = wheel_level(delta);
+    let idx = wheel_bucket(deadline, level);
+    levels[level][idx].push(entry);
+}
+
+/// Error returned by a [`SimpleThreadPool`] / [`SimpleRemote`] spawn entry
+/// point once the pool has begun draining: the task was not queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Draining;
+
+/// Shared bookkeeping behind [`SimpleThreadPool::drain`] / `shutdown`: a flag
+/// that stops new submissions, a second flag that additionally skips the
+/// body of tasks that haven't started running yet, and a count of
+/// admitted-but-not-yet-finished tasks so `drain` knows when the backlog is
+/// empty. Mirrors `ScopeState`'s count-and-condvar shape, but the count only
+/// ever returns to zero instead of being torn down with the struct.
+struct DrainState {
+    draining: AtomicBool,
+    hard: AtomicBool,
+    outstanding: AtomicUsize,
+    lock: Mutex<()>,
+    cond: Condvar,
+}
+
+impl DrainState {
+    fn new() -> Self {
+        DrainState {
+            draining: AtomicBool::new(false),
+            hard: AtomicBool::new(false),
+            outstanding: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Checks whether new submissions are still accepted, without counting
+    /// the caller's task toward the backlog `drain` waits on.
+    fn check(&self) -> Result<(), Draining> {
+        if self.draining.load(Ordering::Acquire) {
+            return Err(Draining);
+        }
+        Ok(())
+    }
+
+    /// Admits one task, counting it toward the backlog `drain` waits on, or
+    /// rejects it if the pool is already draining.
+    ///
+    /// Counts first and checks `draining` after, rolling back on `Err`
+    /// instead of checking first and counting after: otherwise a `drain()`
+    /// racing this call could see `outstanding == 0` and return before the
+    /// increment below ever becomes visible to it, even though the task it
+    /// missed goes on to run — breaking the "blocks until every admitted
+    /// task has finished" contract `drain` exists for. Counting first means
+    /// any `drain()` that can observe `draining == true` here also observes
+    /// this task's increment, so it waits for the rollback's matching
+    /// `task_done()` instead of missing the task entirely.
+    fn enter(&self) -> Result<(), Draining> {
+        self.outstanding.fetch_add(1, Ordering::SeqCst);
+        if self.draining.load(Ordering::SeqCst) {
+            self.task_done();
+            return Err(Draining);
+        }
+        Ok(())
+    }
+
+    /// Whether an admitted task that hasn't started running yet should skip
+    /// its body instead, because `shutdown` (not just `drain`) has been
+    /// called. Checked by each entry point's wrapper right before it would
+    /// otherwise call into the task; a task that already passed this check
+    /// once keeps running to completion even if `shutdown` is called later.
+    fn hard_stopped(&self) -> bool {
+        self.hard.load(Ordering::Acquire)
+    }
+
+    fn task_done(&self) {
+        if self.outstanding.fetch_sub(1, Ordering::SeqCst) == 1 {
+            let _guard = self.lock.lock().unwrap();
+            self.cond.notify_all();
+        }
+    }
+
+    /// Stops admitting new tasks and blocks until every admitted task has
+    /// finished running.
+    fn drain(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+        let mut guard = self.lock.lock().unwrap();
+        while self.outstanding.load(Ordering::SeqCst) != 0 {
+            guard = self.cond.wait(guard).unwrap();
+        }
+    }
+
+    /// Stops admitting new tasks and, unlike `drain`, also marks already
+    /// admitted-but-not-yet-started tasks to skip their body instead of
+    /// running it; returns immediately without waiting for anything to
+    /// finish.
+    fn shutdown(&self) {
+        self.draining.store(true, Ordering::Release);
+        self.hard.store(true, Ordering::Release);
+    }
 }
 
 // For lack of lazy normalization, a wrapper type is needed to avoid cyclic type error.
@@ -183,21 +887,989 @@ impl GlobalQueue for SingleQueue {
     }
 }
 
-pub struct SimpleThreadPool(super::ThreadPool<SingleQueue>);
+/// The actual pool state, shared by [`SimpleThreadPool`] and every
+/// [`SimpleRemote`] cloned from it through a single `Arc`, instead of each
+/// handle carrying its own separate `Arc` per field. Handing out a remote is
+/// then one atomic increment instead of one per field.
+///
+/// `drain` is its own `Arc<DrainState>` rather than folded flat into this
+/// struct: every submitted task closure clones it to check `hard_stopped()`
+/// and call `task_done()`, and that clone must not drag `pool` along with
+/// it. If it did, the last task to finish could be the one that drops the
+/// final `PoolInner` reference — from inside a closure running on one of
+/// `pool`'s own worker threads — and `ThreadPool::drop` joining its workers
+/// would then be a worker joining itself.
+struct PoolInner {
+    pool: super::ThreadPool<SingleQueue>,
+    remote: Remote<SingleQueue>,
+    timers: TimerWheel<SingleQueue>,
+    drain: Arc<DrainState>,
+}
+
+pub struct SimpleThreadPool {
+    inner: Arc<PoolInner>,
+}
 
 impl SimpleThreadPool {
     pub fn from_config(config: Config) -> Self {
         let pool = config.spawn(RunnerFactory::new(), || {
             SingleQueue(crossbeam_deque::Injector::new())
         });
-        Self(pool)
+        let remote = Remote {
+            remote: pool.remote(),
+        };
+        Self {
+            inner: Arc::new(PoolInner {
+                pool,
+                remote,
+                timers: TimerWheel::new(Duration::from_millis(10)),
+                drain: Arc::new(DrainState::new()),
+            }),
+        }
+    }
+
+    pub fn spawn_once(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.pool.spawn_once(move |h| {
+            if !drain.hard_stopped() {
+                // Caught so a panicking task can't permanently wedge every
+                // later `drain()` on this pool by skipping the `task_done()`
+                // below; see `Scope::spawn_once` for the same pattern.
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h)));
+            }
+            drain.task_done();
+        });
+        Ok(())
+    }
+
+    /// Like `spawn_once`, but always runs `t` once admitted, ignoring a later
+    /// `shutdown`'s hard-stop skip. Used by [`Scope`], whose own bookkeeping
+    /// is baked into the closure it hands here and must run for every
+    /// admitted task or [`SimpleThreadPool::scope`] hangs forever waiting on
+    /// an outstanding count that never reaches zero — unlike plain
+    /// `spawn_once`, a scoped task's soundness depends on it actually
+    /// running, not just on the pool's own bookkeeping being consistent.
+    fn spawn_once_unconditional(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.pool.spawn_once(move |h| {
+            t(h);
+            drain.task_done();
+        });
+        Ok(())
+    }
+
+    pub fn spawn_mut(
+        &self,
+        mut t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        // `started` is checked only on this task's first invocation, then
+        // latched so every later rerun skips straight to `t`, regardless of
+        // `shutdown` being called in between — matching `hard_stopped`'s own
+        // doc comment instead of re-testing (and possibly cutting off) a
+        // task that has already started.
+        let mut started = false;
+        self.inner.pool.spawn_mut(move |h| {
+            if !started {
+                if drain.hard_stopped() {
+                    drain.task_done();
+                    return;
+                }
+                started = true;
+            }
+            // Caught so a panicking rerun can't permanently wedge every
+            // later `drain()` on this pool; see `Scope::spawn_mut` for the
+            // same pattern, including why `h.rerun` must be cleared too.
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h))).is_err() {
+                h.rerun = false;
+            }
+            if !h.rerun {
+                drain.task_done();
+            }
+        });
+        Ok(())
+    }
+
+    /// Like `spawn_mut`, but always runs `t` once admitted; see
+    /// `spawn_once_unconditional` for why [`Scope`] needs this instead of
+    /// `spawn_mut`.
+    fn spawn_mut_unconditional(
+        &self,
+        mut t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.pool.spawn_mut(move |h| {
+            t(h);
+            if !h.rerun {
+                drain.task_done();
+            }
+        });
+        Ok(())
+    }
+
+    pub fn spawn_future(
+        &self,
+        f: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.pool.spawn_future(async move {
+            if !drain.hard_stopped() {
+                let _ = CatchUnwindFuture::new(f).await;
+            }
+            drain.task_done();
+        });
+        Ok(())
+    }
+
+    pub fn spawn_once_handle<R>(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) -> R + Send + 'static,
+    ) -> Result<JoinHandle<R>, Draining>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_once(move |h| sender.complete(t(h)))?;
+        Ok(handle)
+    }
+
+    pub fn spawn_future_handle<R>(
+        &self,
+        f: impl Future<Output = R> + Send + 'static,
+    ) -> Result<JoinHandle<R>, Draining>
+    where
+        R: Send + 'static,
+    {
+        let (sender, handle) = JoinSender::new();
+        self.spawn_future(async move { sender.complete(f.await) })?;
+        Ok(handle)
+    }
+
+    /// Stops accepting new submissions and blocks until every task that was
+    /// already admitted when `drain` was called has finished running. From
+    /// then on these same entry points reject new tasks with [`Draining`],
+    /// including when they are called from inside a running task.
+    ///
+    /// Tasks submitted directly through a [`Handle`] or the raw
+    /// [`super::Remote`] from inside an already-running task are not gated:
+    /// only [`SimpleThreadPool`]'s and [`SimpleRemote`]'s own entry points
+    /// check for draining, so in-flight work can keep fanning out internally
+    /// until it naturally finishes; `drain` does not wait for those tasks.
+    /// A registered [`spawn_interval`] timer has
+    /// no completion point and is not part of this backlog at all, so it
+    /// keeps firing across a `drain` call; cancel it yourself if it
+    /// shouldn't outlive the drain.
+    ///
+    /// [`spawn_interval`]: SimpleThreadPool::spawn_interval
+    pub fn drain(&self) {
+        self.inner.drain.drain();
+    }
+
+    /// Stops accepting new submissions and returns immediately, without
+    /// waiting for already-running work to finish. Unlike `drain`, a task
+    /// that was admitted but had not yet started running when `shutdown` was
+    /// called skips its body entirely instead of running to completion; a
+    /// task already underway keeps running regardless of a later `shutdown`.
+    /// Tasks spawned through a [`Scope`] are the exception: once admitted
+    /// they always run.
+    ///
+    /// The task is still popped off the queue as usual — there's no way to
+    /// bulk-discard unstarted [`SchedUnit`]s from outside the scheduler — but
+    /// skipping the body means none of its side effects happen, which is the
+    /// part callers actually care about.
+    ///
+    /// [`SchedUnit`]: super::SchedUnit
+    pub fn shutdown(&self) {
+        self.inner.drain.shutdown();
+    }
+
+    /// Submits `f` to run once, after `delay` has elapsed, via the pool's
+    /// timing wheel.
+    pub fn spawn_once_after(
+        &self,
+        delay: Duration,
+        f: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.remote().spawn_after(delay, f)
+    }
+
+    /// Submits `f` to run repeatedly every `period`, via the pool's timing
+    /// wheel. Returns a [`TimerGuard`] that cancels future firings.
+    pub fn spawn_interval(
+        &self,
+        period: Duration,
+        f: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.remote().spawn_interval(period, f)
+    }
+
+    /// A detachable handle that can spawn tasks and timers on this pool from
+    /// any thread, at the cost of a single atomic increment to share this
+    /// pool's state (see [`PoolInner`]); dropping the last handle reclaims
+    /// it, unlike the leaked [`StaticRemote`].
+    pub fn remote(&self) -> SimpleRemote {
+        SimpleRemote {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Runs `f` with a [`Scope`] that can spawn tasks borrowing data from the
+    /// enclosing stack frame. Blocks until every task spawned through the
+    /// scope has finished before returning, so the borrows are provably
+    /// valid for the scope's whole lifetime — including when `f` itself
+    /// panics: the body is caught here, the outstanding-wait loop always
+    /// runs, and only then is a panic (the body's own, or else a scoped
+    /// task's) resumed, so no borrowed stack data can still be in use by a
+    /// worker thread once this call actually returns or unwinds.
+    pub fn scope<'env, F, R>(&'env self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&Scope<'scope, 'env>) -> R,
+    {
+        let state = Arc::new(ScopeState {
+            outstanding: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            cond: Condvar::new(),
+            panic: Mutex::new(None),
+        });
+        let scope = Scope {
+            pool: self,
+            state: state.clone(),
+            _scope: PhantomData,
+            _env: PhantomData,
+        };
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&scope)));
+
+        let mut guard = state.lock.lock().unwrap();
+        while state.outstanding.load(Ordering::Acquire) != 0 {
+            guard = state.cond.wait(guard).unwrap();
+        }
+        drop(guard);
+
+        let task_panic = state.panic.lock().unwrap().take();
+        match result {
+            Ok(result) => {
+                if let Some(payload) = task_panic {
+                    std::panic::resume_unwind(payload);
+                }
+                result
+            }
+            // The body's own panic takes priority; a scoped task's panic
+            // (if any) is superseded rather than reported on top of it.
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+struct ScopeState {
+    outstanding: AtomicUsize,
+    lock: Mutex<()>,
+    cond: Condvar,
+    panic: Mutex<Option<Box<dyn std::any::Any + Send + 'static>>>,
+}
+
+impl ScopeState {
+    fn task_done(&self, result: std::thread::Result<()>) {
+        if let Err(payload) = result {
+            let mut guard = self.panic.lock().unwrap();
+            if guard.is_none() {
+                *guard = Some(payload);
+            }
+        }
+        if self.outstanding.fetch_sub(1, Ordering::SeqCst) == 1 {
+            let _guard = self.lock.lock().unwrap();
+            self.cond.notify_all();
+        }
+    }
+}
+
+/// A scope created by [`SimpleThreadPool::scope`]. Tasks spawned through it
+/// may borrow data with lifetime `'scope` instead of requiring `'static`,
+/// since the scope only returns once every such task has completed.
+pub struct Scope<'scope, 'env: 'scope> {
+    pool: &'env SimpleThreadPool,
+    state: Arc<ScopeState>,
+    _scope: PhantomData<&'scope mut &'scope ()>,
+    _env: PhantomData<&'env ()>,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    pub fn spawn_once<F>(&self, f: F) -> Result<(), Draining>
+    where
+        F: FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'scope,
+    {
+        self.state.outstanding.fetch_add(1, Ordering::SeqCst);
+        let state = self.state.clone();
+        // SAFETY: `scope` blocks until `outstanding` drops to zero, which only
+        // happens after this closure has run to completion, so the borrows
+        // captured by `f` remain valid for as long as the pool can call it.
+        let f: Box<dyn FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'scope> = Box::new(f);
+        let f: Box<dyn FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static> =
+            unsafe { std::mem::transmute(f) };
+        let submitted = self.pool.spawn_once_unconditional(move |h| {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(h)));
+            state.task_done(result);
+        });
+        if submitted.is_err() {
+            // The pool is draining and never ran the closure above, so the
+            // `outstanding` bump it would have cleared must be undone here.
+            self.state.task_done(Ok(()));
+        }
+        submitted
+    }
+
+    pub fn spawn_mut<F>(&self, f: F) -> Result<(), Draining>
+    where
+        F: FnMut(&mut Handle<'_, SingleQueue>) + Send + 'scope,
+    {
+        self.state.outstanding.fetch_add(1, Ordering::SeqCst);
+        let state = self.state.clone();
+        // SAFETY: see `spawn_once` above.
+        let f: Box<dyn FnMut(&mut Handle<'_, SingleQueue>) + Send + 'scope> = Box::new(f);
+        let mut f: Box<dyn FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static> =
+            unsafe { std::mem::transmute(f) };
+        let submitted = self.pool.spawn_mut_unconditional(move |h| {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(h)));
+            if result.is_err() {
+                // A panic mid-rerun must not leave `h.rerun` set: the wrapper
+                // closure itself returns normally, so `Runner::handle` only
+                // knows to stop re-invoking it by reading this flag.
+                h.rerun = false;
+            }
+            let finished = result.is_err() || !h.rerun;
+            if finished {
+                state.task_done(result.map(|_| ()));
+            }
+        });
+        if submitted.is_err() {
+            self.state.task_done(Ok(()));
+        }
+        submitted
+    }
+}
+
+/// A detachable handle to a [`SimpleThreadPool`] that can submit tasks and
+/// timers from any thread.
+pub struct SimpleRemote {
+    inner: Arc<PoolInner>,
+}
+
+impl SimpleRemote {
+    pub fn spawn_once(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.remote.spawn_once(move |h| {
+            if !drain.hard_stopped() {
+                // See `SimpleThreadPool::spawn_once` for why this is caught.
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h)));
+            }
+            drain.task_done();
+        });
+        Ok(())
+    }
+
+    pub fn spawn_mut(
+        &self,
+        mut t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        // See `SimpleThreadPool::spawn_mut` for why this is only checked on
+        // the task's first invocation.
+        let mut started = false;
+        self.inner.remote.spawn_mut(move |h| {
+            if !started {
+                if drain.hard_stopped() {
+                    drain.task_done();
+                    return;
+                }
+                started = true;
+            }
+            // See `SimpleThreadPool::spawn_mut` for why this is caught and
+            // `h.rerun` cleared on the panic path.
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h))).is_err() {
+                h.rerun = false;
+            }
+            if !h.rerun {
+                drain.task_done();
+            }
+        });
+        Ok(())
+    }
+
+    pub fn spawn_future(
+        &self,
+        f: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        self.inner.remote.spawn_future(async move {
+            if !drain.hard_stopped() {
+                let _ = CatchUnwindFuture::new(f).await;
+            }
+            drain.task_done();
+        });
+        Ok(())
+    }
+
+    /// Submits `f` to run once, after `delay` has elapsed.
+    pub fn spawn_after(
+        &self,
+        delay: Duration,
+        f: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.inner.drain.enter()?;
+        let drain = self.inner.drain.clone();
+        let mut f = Some(f);
+        Ok(self.inner.timers.submit(
+            self.inner.remote.remote.clone(),
+            delay,
+            None,
+            Box::new(move |h| {
+                if !drain.hard_stopped() {
+                    if let Some(f) = f.take() {
+                        // See `SimpleThreadPool::spawn_once` for why this is
+                        // caught.
+                        let _ =
+                            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(h)));
+                    }
+                }
+                drain.task_done();
+            }),
+        ))
     }
 
-    pub fn spawn_once(&self, t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static) {
+    /// Submits `f` to run repeatedly every `period`. Returns a
+    /// [`TimerGuard`] that cancels future firings.
+    ///
+    /// A periodic timer has no natural completion point, so — unlike the
+    /// other entry points here — it is not counted toward [`drain`]'s
+    /// backlog; only registering a *new* one is rejected once the pool is
+    /// draining.
+    ///
+    /// [`drain`]: SimpleThreadPool::drain
+    pub fn spawn_interval(
+        &self,
+        period: Duration,
+        mut f: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.inner.drain.check()?;
+        let drain = self.inner.drain.clone();
+        Ok(self.inner.timers.submit(
+            self.inner.remote.remote.clone(),
+            period,
+            Some(period),
+            Box::new(move |h| {
+                if !drain.hard_stopped() {
+                    f(h);
+                }
+            }),
+        ))
+    }
+}
+
+/// A [`SimpleThreadPool`] leaked to `'static` through [`SimpleThreadPool::leak`].
+///
+/// Every [`SimpleRemote`] carries an `Arc` clone of the pool's shared
+/// [`PoolInner`], so handing one out — and every `spawn_once`/`spawn_mut`/
+/// `spawn_future` call made through it — costs one atomic increment plus a
+/// matching decrement on completion. `StaticSimpleThreadPool` hands out a
+/// [`StaticRemote`] that borrows `PoolInner` as a `&'static` reference
+/// instead: because the pool is leaked rather than reference-counted, that
+/// reference is always valid, so `StaticRemote`'s own spawn methods skip the
+/// `Arc` clone entirely rather than just avoiding it on the handle itself.
+/// For tests and other short-lived pools, prefer plain [`SimpleThreadPool`]
+/// and its `Arc`-backed [`SimpleRemote`], which drop normally.
+///
+/// # Known limitation: no `Box` reuse yet
+///
+/// This only delivers the `Arc`-bookkeeping half of what the original
+/// request asked for. Each task submitted still allocates its own
+/// `Box<dyn FnOnce/FnMut>` inside `Task<G>`, same as on a non-leaked pool —
+/// there is no object-pool/free-list that lets a completed `Once` task
+/// return its box for reuse instead of freeing it, which is the half of the
+/// request the cited static-executor benchmarks' throughput gain actually
+/// comes from. That needs a hook in the scheduler that owns
+/// `SchedUnit<Task<G>>`, which lives outside this module and isn't touched
+/// here. **This request is not fully delivered as a result** — treat the
+/// free-list as a separate, still-open follow-up rather than folding it
+/// into this one silently; it needs its own review/sign-off once the
+/// scheduler-side hook lands.
+pub struct StaticSimpleThreadPool(SimpleThreadPool);
+
+impl SimpleThreadPool {
+    /// Leaks `self`, returning a `'static` reference to it for use as, e.g.,
+    /// a process-global pool. There is no way to reclaim the memory
+    /// afterwards; pools that need to be dropped (most tests) should keep
+    /// using a plain `SimpleThreadPool` instead — its [`SimpleRemote`] is
+    /// already just a one-`Arc`-clone handle, reclaimed on `Drop` once every
+    /// clone is gone, without requiring `leak` at all.
+    pub fn leak(self) -> &'static StaticSimpleThreadPool {
+        Box::leak(Box::new(StaticSimpleThreadPool(self)))
+    }
+
+    /// Test-only alternative to [`leak`] for exercising `StaticRemote`'s
+    /// pointer-cheap path without leaking memory for the rest of the
+    /// process: returns the same `'static` reference `leak` would, paired
+    /// with a [`LeakedPoolGuard`] that frees the boxed pool once dropped.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference, and every [`StaticRemote`] obtained from it,
+    /// must be gone before the guard is dropped — same invariant `unsafe`
+    /// code asks of any borrow whose real lifetime is shorter than the
+    /// `'static` it's been given.
+    #[cfg(test)]
+    pub fn leak_for_test(self) -> (&'static StaticSimpleThreadPool, LeakedPoolGuard) {
+        let boxed = Box::into_raw(Box::new(StaticSimpleThreadPool(self)));
+        // SAFETY: `boxed` stays valid until `LeakedPoolGuard::drop` frees
+        // it; see the caller obligations documented above.
+        let pool = unsafe { &*boxed };
+        (pool, LeakedPoolGuard(boxed))
+    }
+}
+
+/// Reclaims the pool leaked by [`SimpleThreadPool::leak_for_test`] when
+/// dropped. See that method's safety section for the obligation this
+/// relies on.
+#[cfg(test)]
+pub struct LeakedPoolGuard(*mut StaticSimpleThreadPool);
+
+#[cfg(test)]
+impl Drop for LeakedPoolGuard {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` was produced by `Box::into_raw` in
+        // `leak_for_test` and is reclaimed at most once, here.
+        unsafe {
+            drop(Box::from_raw(self.0));
+        }
+    }
+}
+
+impl StaticSimpleThreadPool {
+    pub fn spawn_once(
+        &'static self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
         self.0.spawn_once(t)
     }
 
-    pub fn spawn_mut(&self, t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static) {
+    pub fn spawn_mut(
+        &'static self,
+        t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
         self.0.spawn_mut(t)
     }
+
+    pub fn spawn_future(
+        &'static self,
+        f: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), Draining> {
+        self.0.spawn_future(f)
+    }
+
+    pub fn spawn_once_handle<R>(
+        &'static self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) -> R + Send + 'static,
+    ) -> Result<JoinHandle<R>, Draining>
+    where
+        R: Send + 'static,
+    {
+        self.0.spawn_once_handle(t)
+    }
+
+    pub fn spawn_future_handle<R>(
+        &'static self,
+        f: impl Future<Output = R> + Send + 'static,
+    ) -> Result<JoinHandle<R>, Draining>
+    where
+        R: Send + 'static,
+    {
+        self.0.spawn_future_handle(f)
+    }
+
+    pub fn spawn_once_after(
+        &'static self,
+        delay: Duration,
+        f: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.0.spawn_once_after(delay, f)
+    }
+
+    pub fn spawn_interval(
+        &'static self,
+        period: Duration,
+        f: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        self.0.spawn_interval(period, f)
+    }
+
+    pub fn drain(&'static self) {
+        self.0.drain()
+    }
+
+    pub fn shutdown(&'static self) {
+        self.0.shutdown()
+    }
+
+    pub fn scope<'env, F, R>(&'env self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&Scope<'scope, 'env>) -> R,
+    {
+        self.0.scope(f)
+    }
+
+    /// A `'static` handle that can submit tasks and timers on this pool from
+    /// any thread, at the cost of a pointer copy instead of the `Arc` clones
+    /// behind [`SimpleRemote`].
+    pub fn remote(&'static self) -> StaticRemote {
+        StaticRemote { pool: self }
+    }
+}
+
+/// A cheap `'static` handle to a [`StaticSimpleThreadPool`]. See
+/// [`StaticSimpleThreadPool`]'s docs for why this exists instead of
+/// [`SimpleRemote`].
+#[derive(Clone, Copy)]
+pub struct StaticRemote {
+    pool: &'static StaticSimpleThreadPool,
+}
+
+impl StaticRemote {
+    /// The pool's shared state, borrowed for `'static` instead of `Arc`-cloned:
+    /// since `self.pool` is itself a leaked `&'static` reference, so is every
+    /// field reachable through it, and no refcount needs touching to use one.
+    fn inner(&self) -> &'static PoolInner {
+        &*self.pool.0.inner
+    }
+
+    pub fn spawn_once(
+        &self,
+        t: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        let inner = self.inner();
+        inner.drain.enter()?;
+        inner.remote.spawn_once(move |h| {
+            if !inner.drain.hard_stopped() {
+                // See `SimpleThreadPool::spawn_once` for why this is caught.
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h)));
+            }
+            inner.drain.task_done();
+        });
+        Ok(())
+    }
+
+    pub fn spawn_mut(
+        &self,
+        mut t: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<(), Draining> {
+        let inner = self.inner();
+        inner.drain.enter()?;
+        // See `SimpleThreadPool::spawn_mut` for why this is only checked on
+        // the task's first invocation.
+        let mut started = false;
+        inner.remote.spawn_mut(move |h| {
+            if !started {
+                if inner.drain.hard_stopped() {
+                    inner.drain.task_done();
+                    return;
+                }
+                started = true;
+            }
+            // See `SimpleThreadPool::spawn_mut` for why this is caught and
+            // `h.rerun` cleared on the panic path.
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t(h))).is_err() {
+                h.rerun = false;
+            }
+            if !h.rerun {
+                inner.drain.task_done();
+            }
+        });
+        Ok(())
+    }
+
+    pub fn spawn_future(
+        &self,
+        f: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), Draining> {
+        let inner = self.inner();
+        inner.drain.enter()?;
+        inner.remote.spawn_future(async move {
+            if !inner.drain.hard_stopped() {
+                let _ = CatchUnwindFuture::new(f).await;
+            }
+            inner.drain.task_done();
+        });
+        Ok(())
+    }
+
+    pub fn spawn_after(
+        &self,
+        delay: Duration,
+        f: impl FnOnce(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        let inner = self.inner();
+        inner.drain.enter()?;
+        let mut f = Some(f);
+        Ok(inner.timers.submit(
+            inner.remote.remote.clone(),
+            delay,
+            None,
+            Box::new(move |h| {
+                if !inner.drain.hard_stopped() {
+                    if let Some(f) = f.take() {
+                        // See `SimpleThreadPool::spawn_once` for why this is
+                        // caught.
+                        let _ =
+                            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(h)));
+                    }
+                }
+                inner.drain.task_done();
+            }),
+        ))
+    }
+
+    pub fn spawn_interval(
+        &self,
+        period: Duration,
+        mut f: impl FnMut(&mut Handle<'_, SingleQueue>) + Send + 'static,
+    ) -> Result<TimerGuard, Draining> {
+        let inner = self.inner();
+        inner.drain.check()?;
+        Ok(inner.timers.submit(
+            inner.remote.remote.clone(),
+            period,
+            Some(period),
+            Box::new(move |h| {
+                if !inner.drain.hard_stopped() {
+                    f(h);
+                }
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::mpsc;
+
+    fn pool() -> SimpleThreadPool {
+        SimpleThreadPool::from_config(Config::default())
+    }
+
+    #[test]
+    fn poll_handles_synchronous_self_wake() {
+        // Wakes itself once from inside its own `poll`, the way `yield_now`
+        // does. Under the old `Mutex<Option<..>>` completion sentinel this
+        // raced with the re-enqueued poll: the second poll would see the
+        // future already taken and wrongly report the task done, hanging
+        // this test forever instead of completing it.
+        struct YieldOnce(bool);
+        impl Future for YieldOnce {
+            type Output = ();
+            fn poll(mut self: Pin<&mut Self>, cx: &mut StdContext<'_>) -> StdPoll<()> {
+                if self.0 {
+                    StdPoll::Ready(())
+                } else {
+                    self.0 = true;
+                    cx.waker().wake_by_ref();
+                    StdPoll::Pending
+                }
+            }
+        }
+
+        let pool = pool();
+        let (tx, rx) = mpsc::channel();
+        pool.spawn_future(async move {
+            YieldOnce(false).await;
+            tx.send(()).unwrap();
+        })
+        .unwrap();
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("self-waking future never completed");
+    }
+
+    #[test]
+    fn spawn_once_handle_delivers_the_computed_value() {
+        let pool = pool();
+        let handle = pool.spawn_once_handle(|_| 21 * 2).unwrap();
+        assert_eq!(handle.join(), Ok(42));
+    }
+
+    #[test]
+    fn spawn_future_handle_delivers_the_computed_value() {
+        let pool = pool();
+        let handle = pool.spawn_future_handle(async { 21 * 2 }).unwrap();
+        assert_eq!(handle.join(), Ok(42));
+    }
+
+    #[test]
+    fn join_handle_resolves_to_cancelled_when_its_task_is_dropped_unrun() {
+        // `shutdown` makes the pool skip the body of a task admitted before
+        // it but not yet started, so the `JoinSender` stashed inside it is
+        // dropped without ever calling `complete`, which should resolve the
+        // handle to `Cancelled` instead of hanging `join` forever. The timer
+        // keeps the task from starting until well after `shutdown()`.
+        let pool = pool();
+        let (sender, handle) = JoinSender::new();
+        let _guard = pool
+            .spawn_once_after(Duration::from_millis(100), move |_| sender.complete(42))
+            .unwrap();
+        pool.shutdown();
+        pool.drain();
+        assert_eq!(handle.join(), Err(Cancelled));
+    }
+
+    #[test]
+    fn scope_spawn_once_can_borrow_stack_data() {
+        let pool = pool();
+        let data = vec![1, 2, 3];
+        let sum = AtomicUsize::new(0);
+        pool.scope(|s| {
+            s.spawn_once(|_| {
+                sum.fetch_add(data.iter().sum::<i32>() as usize, Ordering::SeqCst);
+            })
+            .unwrap();
+        });
+        assert_eq!(sum.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn scope_spawn_mut_can_borrow_stack_data() {
+        let pool = pool();
+        let runs = AtomicUsize::new(0);
+        pool.scope(|s| {
+            s.spawn_mut(|h| {
+                if runs.fetch_add(1, Ordering::SeqCst) + 1 < 3 {
+                    h.rerun();
+                }
+            })
+            .unwrap();
+        });
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn shutdown_lets_an_already_admitted_scoped_task_finish() {
+        // A task admitted into `scope` just before `shutdown()` runs must
+        // still execute (Scope's own bookkeeping is baked into the closure
+        // the pool calls), or `scope` blocks forever waiting on an
+        // outstanding count that never reaches zero.
+        let pool = pool();
+        let ran = AtomicUsize::new(0);
+        pool.scope(|s| {
+            s.spawn_once(|_| {
+                thread::sleep(Duration::from_millis(20));
+                ran.fetch_add(1, Ordering::SeqCst);
+            })
+            .unwrap();
+            pool.shutdown();
+        });
+        assert_eq!(ran.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn shutdown_skips_not_yet_started_plain_tasks() {
+        // Unlike a scoped task, a plain task admitted before `shutdown()` but
+        // not yet started when it is called should have its body skipped
+        // rather than run to completion. The timer keeps the task from
+        // starting until well after `shutdown()`.
+        let pool = pool();
+        let ran = Arc::new(AtomicUsize::new(0));
+        let ran_in_task = ran.clone();
+        let _guard = pool
+            .spawn_once_after(Duration::from_millis(100), move |_| {
+                ran_in_task.fetch_add(1, Ordering::SeqCst);
+            })
+            .unwrap();
+        pool.shutdown();
+        pool.drain();
+        assert_eq!(ran.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn dropping_the_pool_while_a_task_is_in_flight_does_not_hang() {
+        // Regression test: task closures must only hold `Arc<DrainState>`,
+        // never the whole `Arc<PoolInner>` (and the `ThreadPool` inside it).
+        // Otherwise the last task to finish could be the one that drops the
+        // final `PoolInner` reference -- from inside a closure running on
+        // one of that same `ThreadPool`'s own worker threads, which would
+        // make `ThreadPool::drop` joining its workers a worker joining
+        // itself.
+        let pool = pool();
+        let remote = pool.remote();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (done_tx, done_rx) = mpsc::channel();
+        remote
+            .spawn_once(move |_| {
+                started_tx.send(()).unwrap();
+                thread::sleep(Duration::from_millis(50));
+                done_tx.send(()).unwrap();
+            })
+            .unwrap();
+        started_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("task never started");
+        drop(remote);
+        drop(pool);
+        done_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("task never finished after the pool was dropped mid-flight");
+    }
+
+    #[test]
+    fn timer_wheel_fires_after_delay() {
+        let pool = pool();
+        let (tx, rx) = mpsc::channel();
+        let _guard = pool
+            .spawn_once_after(Duration::from_millis(10), move |_| {
+                tx.send(()).unwrap();
+            })
+            .unwrap();
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("timer never fired");
+    }
+
+    #[test]
+    fn leaked_pool_runs_tasks_through_its_static_remote() {
+        let pool = pool().leak();
+        let (tx, rx) = mpsc::channel();
+        pool.remote()
+            .spawn_once(move |_| {
+                tx.send(()).unwrap();
+            })
+            .unwrap();
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("leaked pool's StaticRemote never ran the task");
+    }
+
+    #[test]
+    fn leak_for_test_reclaims_the_pool_once_the_guard_drops() {
+        let (pool, guard) = pool().leak_for_test();
+        let (tx, rx) = mpsc::channel();
+        pool.remote()
+            .spawn_once(move |_| {
+                tx.send(()).unwrap();
+            })
+            .unwrap();
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("leak_for_test's StaticRemote never ran the task");
+        pool.drain();
+        drop(guard);
+    }
 }